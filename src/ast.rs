@@ -0,0 +1,318 @@
+//! The `ast` subcommand: a shallow export of a package's module/struct/
+//! function shapes (no expression bodies — see [`crate::full`] for that).
+
+use crate::schema::{self, ItemPath};
+use crate::span::{SpanJson, SpanResolver};
+use move_model::model::{EnvDisplay, FieldEnv, FunctionEnv, GlobalEnv, ModuleEnv, StructEnv};
+use move_model::ty::TypeDisplayContext;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+// JSON structures
+//
+// `index` holds every module/struct/function/field keyed by its stable
+// id; nested containers (e.g. `ModuleJson::structs`) hold only ids, not
+// inline copies, so a consumer resolves a reference by looking it up in
+// `index` once instead of re-walking the tree.
+
+#[derive(Serialize)]
+pub struct AstJson {
+    format_version: u32,
+    root: String,
+    index: BTreeMap<String, Item>,
+    paths: BTreeMap<String, ItemPath>,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "item_kind", rename_all = "snake_case")]
+enum Item {
+    Package { modules: Vec<String> },
+    Module(ModuleJson),
+    Struct(StructJson),
+    Function(FunctionJson),
+    Field(FieldJson),
+}
+
+#[derive(Serialize)]
+struct ModuleJson {
+    id: String,
+    name: String,
+    address: String,
+    is_script: bool,
+    structs: Vec<String>,
+    functions: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    loc: Option<SpanJson>,
+}
+
+#[derive(Serialize)]
+struct StructJson {
+    id: String,
+    module: String,
+    name: String,
+    abilities: Vec<String>,
+    type_params: Vec<String>,
+    fields: Vec<String>,
+    is_native: bool,
+    is_ghost_memory: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    loc: Option<SpanJson>,
+}
+
+#[derive(Serialize)]
+struct FieldJson {
+    id: String,
+    name: String,
+    ty: String,
+    offset: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    variant: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    loc: Option<SpanJson>,
+}
+
+#[derive(Serialize)]
+struct FunctionJson {
+    id: String,
+    module: String,
+    name: String,
+    visibility: String,
+    kind: String,
+    type_params: Vec<String>,
+    parameters: Vec<ParamJson>,
+    results: Vec<String>,
+    is_native: bool,
+    is_intrinsic: bool,
+    is_entry: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    loc: Option<SpanJson>,
+}
+
+#[derive(Serialize)]
+struct ParamJson {
+    name: String,
+    ty: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    loc: Option<SpanJson>,
+}
+
+// AST traversal
+
+pub fn build_ast(env: &GlobalEnv) -> AstJson {
+    let resolver = SpanResolver::new(env);
+    let mut index = BTreeMap::new();
+    let mut paths = BTreeMap::new();
+
+    let modules = env
+        .get_modules()
+        .map(|m| module_to_json(m, &resolver, &mut index, &mut paths))
+        .collect();
+
+    index.insert(schema::ROOT_ID.to_string(), Item::Package { modules });
+    paths.insert(
+        schema::ROOT_ID.to_string(),
+        ItemPath {
+            kind: "package".to_string(),
+            path: vec![],
+        },
+    );
+
+    AstJson {
+        format_version: schema::FORMAT_VERSION,
+        root: schema::ROOT_ID.to_string(),
+        index,
+        paths,
+    }
+}
+
+fn module_to_json(
+    m: ModuleEnv,
+    resolver: &SpanResolver,
+    index: &mut BTreeMap<String, Item>,
+    paths: &mut BTreeMap<String, ItemPath>,
+) -> String {
+    let address = m.env.display(m.get_name().addr()).to_string();
+    let name = m.get_name().name().display(m.symbol_pool()).to_string();
+    let id = schema::module_id(&address, &name);
+
+    let structs = m
+        .get_structs()
+        .map(|s| struct_to_json(s, &id, &[name.clone()], resolver, index, paths))
+        .collect();
+    let functions = m
+        .get_functions()
+        .map(|f| function_to_json(f, &id, &[name.clone()], resolver, index, paths))
+        .collect();
+
+    index.insert(
+        id.clone(),
+        Item::Module(ModuleJson {
+            id: id.clone(),
+            name: name.clone(),
+            address,
+            is_script: m.is_script_module(),
+            structs,
+            functions,
+            loc: resolver.resolve(&m.get_loc()),
+        }),
+    );
+    paths.insert(
+        id.clone(),
+        ItemPath {
+            kind: "module".to_string(),
+            path: vec![name],
+        },
+    );
+    id
+}
+
+fn struct_to_json(
+    s: StructEnv,
+    module_id: &str,
+    module_path: &[String],
+    resolver: &SpanResolver,
+    index: &mut BTreeMap<String, Item>,
+    paths: &mut BTreeMap<String, ItemPath>,
+) -> String {
+    let ctx = s.get_type_display_ctx();
+    let name = s.get_name().display(s.symbol_pool()).to_string();
+    let id = schema::struct_id(module_id, &name);
+    let path: Vec<String> = module_path.iter().cloned().chain([name.clone()]).collect();
+
+    let fields = s
+        .get_fields()
+        .map(|f| field_to_json(&ctx, f, &id, &path, resolver, index, paths))
+        .collect();
+
+    index.insert(
+        id.clone(),
+        Item::Struct(StructJson {
+            id: id.clone(),
+            module: module_id.to_string(),
+            name,
+            abilities: s
+                .get_abilities()
+                .into_iter()
+                .map(|a| format!("{a:?}"))
+                .collect(),
+            type_params: s
+                .get_type_parameters()
+                .iter()
+                .map(|tp| tp.0.display(s.symbol_pool()).to_string())
+                .collect(),
+            fields,
+            is_native: s.is_native(),
+            is_ghost_memory: s.is_ghost_memory(),
+            loc: resolver.resolve(&s.get_loc()),
+        }),
+    );
+    paths.insert(
+        id.clone(),
+        ItemPath {
+            kind: "struct".to_string(),
+            path,
+        },
+    );
+    id
+}
+
+fn field_to_json(
+    ctx: &TypeDisplayContext,
+    f: FieldEnv,
+    struct_id: &str,
+    struct_path: &[String],
+    resolver: &SpanResolver,
+    index: &mut BTreeMap<String, Item>,
+    paths: &mut BTreeMap<String, ItemPath>,
+) -> String {
+    let name = f.get_name().display(ctx.env.symbol_pool()).to_string();
+    let id = schema::field_id(struct_id, &name);
+    let path: Vec<String> = struct_path
+        .iter()
+        .cloned()
+        .chain([name.clone()])
+        .collect();
+
+    index.insert(
+        id.clone(),
+        Item::Field(FieldJson {
+            id: id.clone(),
+            name,
+            ty: f.get_type().display(ctx).to_string(),
+            offset: f.get_offset(),
+            variant: f
+                .get_variant()
+                .map(|v| v.display(ctx.env.symbol_pool()).to_string()),
+            loc: resolver.resolve(&f.get_loc()),
+        }),
+    );
+    paths.insert(
+        id.clone(),
+        ItemPath {
+            kind: "field".to_string(),
+            path,
+        },
+    );
+    id
+}
+
+fn function_to_json(
+    f: FunctionEnv,
+    module_id: &str,
+    module_path: &[String],
+    resolver: &SpanResolver,
+    index: &mut BTreeMap<String, Item>,
+    paths: &mut BTreeMap<String, ItemPath>,
+) -> String {
+    let ctx = f.get_type_display_ctx();
+    let name = f.get_name().display(f.symbol_pool()).to_string();
+    let id = schema::function_id(module_id, &name);
+    let path: Vec<String> = module_path.iter().cloned().chain([name.clone()]).collect();
+    // Parameters don't carry their own `Loc` in the model; the
+    // function's own location is used as a conservative stand-in until
+    // per-parameter spans are exposed.
+    let fn_loc = resolver.resolve(&f.get_loc());
+
+    index.insert(
+        id.clone(),
+        Item::Function(FunctionJson {
+            id: id.clone(),
+            module: module_id.to_string(),
+            name,
+            visibility: format!("{:?}", f.visibility()),
+            kind: format!("{:?}", f.get_kind()),
+            type_params: f
+                .get_type_parameters()
+                .iter()
+                .map(|tp| tp.0.display(f.symbol_pool()).to_string())
+                .collect(),
+            parameters: f
+                .get_parameters()
+                .into_iter()
+                .map(|p| ParamJson {
+                    name: p.0.display(f.symbol_pool()).to_string(),
+                    ty: p.1.display(&ctx).to_string(),
+                    loc: fn_loc.clone(),
+                })
+                .collect(),
+            results: f
+                .get_result_type()
+                .flatten()
+                .into_iter()
+                .map(|ty| ty.display(&ctx).to_string())
+                .collect(),
+            is_native: f.is_native(),
+            is_intrinsic: f.is_intrinsic(),
+            is_entry: f.is_entry(),
+            loc: fn_loc,
+        }),
+    );
+    paths.insert(
+        id.clone(),
+        ItemPath {
+            kind: "function".to_string(),
+            path,
+        },
+    );
+    id
+}
@@ -0,0 +1,581 @@
+//! The `full` subcommand: the deep AST export, including expression
+//! bodies (see [`exp_to_json`]), attributes, and specs (see [`crate::spec`],
+//! which reuses [`exp_to_json`] to serialize condition expressions).
+
+use crate::schema::{self, ItemPath};
+use crate::span::{SpanJson, SpanResolver};
+use crate::spec::{module_spec_to_json, spec_to_json, SpecJson};
+use move_model::{
+    ast::{Attribute, Exp, Pattern},
+    model::{EnvDisplay, FieldEnv, FunctionEnv, GlobalEnv, ModuleEnv, StructEnv},
+    ty::TypeDisplayContext,
+};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+#[derive(Serialize)]
+struct AttrJson {
+    name: String,
+    value: String,
+}
+
+/// A round-trippable tag for every `ExpData` variant (plus the synthetic
+/// `MatchArm` node used to carry a match arm's pattern/guard/body). Kept
+/// as a real enum rather than an ad hoc string so a consumer can match on
+/// it exhaustively instead of parsing `Debug` output.
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ExpKind {
+    Invalid,
+    Value,
+    LocalVar,
+    Temporary,
+    Call,
+    Invoke,
+    Lambda,
+    Quant,
+    Block,
+    IfElse,
+    Match,
+    MatchArm,
+    Return,
+    Sequence,
+    Loop,
+    LoopContinue,
+    LoopBreak,
+    Assign,
+    Mutate,
+    SpecBlock,
+}
+
+#[derive(Serialize)]
+pub(crate) struct ExpJson {
+    node_id: usize,
+    kind: ExpKind,
+    /// Set for `Call` (the `Operation` being applied) and `Quant` (the quantifier kind).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    operation: Option<String>,
+    /// Set for leaf nodes: a literal (`Value`), a variable name (`LocalVar`),
+    /// a temporary's index (`Temporary`), or the loop nesting count
+    /// (`LoopContinue`/`LoopBreak`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+    /// Variables bound by this node's pattern (`Block`, `Lambda`, `Assign`, `Quant`, `MatchArm`).
+    bound_vars: Vec<String>,
+    children: Vec<ExpJson>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    loc: Option<SpanJson>,
+    /// Set only for `SpecBlock`: the inline `spec {}`'s conditions, with the
+    /// same structure as a function's or struct's `spec` field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    spec: Option<SpecJson>,
+}
+
+#[derive(Serialize)]
+struct FunJson {
+    id: String,
+    module: String,
+    name: String,
+    params: Vec<ParamJson>,
+    ret: String,
+    attrs: Vec<AttrJson>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<ExpJson>,
+    spec: SpecJson,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    loc: Option<SpanJson>,
+}
+
+#[derive(Serialize)]
+struct ParamJson {
+    name: String,
+    ty: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    loc: Option<SpanJson>,
+}
+
+#[derive(Serialize)]
+struct StructJson {
+    id: String,
+    module: String,
+    name: String,
+    fields: Vec<String>,
+    attrs: Vec<AttrJson>,
+    spec: SpecJson,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    loc: Option<SpanJson>,
+}
+
+#[derive(Serialize)]
+struct FieldJson {
+    id: String,
+    name: String,
+    ty: String,
+    offset: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    variant: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    loc: Option<SpanJson>,
+}
+
+#[derive(Serialize)]
+struct ModuleJson {
+    id: String,
+    name: String,
+    address: String,
+    structs: Vec<String>,
+    functions: Vec<String>,
+    attrs: Vec<AttrJson>,
+    spec: SpecJson,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    loc: Option<SpanJson>,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "item_kind", rename_all = "snake_case")]
+enum Item {
+    Package { modules: Vec<String> },
+    Module(ModuleJson),
+    Struct(StructJson),
+    Function(FunJson),
+    Field(FieldJson),
+}
+
+/// Stable, versioned export of a package's AST. `index` holds every
+/// module/struct/function keyed by its stable id; `paths` resolves an id
+/// to its item kind and human-readable path segments without re-walking
+/// `index`. Bump
+/// `format_version` (see `schema::FORMAT_VERSION`) whenever the shape of
+/// these items changes.
+#[derive(Serialize)]
+pub struct PackageJson {
+    format_version: u32,
+    root: String,
+    index: BTreeMap<String, Item>,
+    paths: BTreeMap<String, ItemPath>,
+}
+
+fn attrs_to_json(attrs: &[Attribute], env: &GlobalEnv) -> Vec<AttrJson> {
+    attrs
+        .iter()
+        .map(|a| AttrJson {
+            name: env.symbol_pool().string(a.name()).to_string(),
+            value: format!("{a:?}"),
+        })
+        .collect()
+}
+
+/// Flattens the variables bound by a pattern (`let (x, y) = ..`, a lambda
+/// parameter, a struct/variant destructuring arm, etc.) in binding order.
+fn pattern_vars(p: &Pattern, env: &GlobalEnv) -> Vec<String> {
+    match p {
+        Pattern::Var(_, sym) => vec![sym.display(env.symbol_pool()).to_string()],
+        Pattern::Wildcard(_) => vec![],
+        Pattern::Tuple(_, pats) => pats.iter().flat_map(|p| pattern_vars(p, env)).collect(),
+        Pattern::Struct(_, _, pats) => pats.iter().flat_map(|p| pattern_vars(p, env)).collect(),
+        _ => vec![],
+    }
+}
+
+fn leaf(node_id: usize, kind: ExpKind, value: Option<String>, loc: Option<SpanJson>) -> ExpJson {
+    ExpJson {
+        node_id,
+        kind,
+        operation: None,
+        value,
+        bound_vars: vec![],
+        children: vec![],
+        loc,
+        spec: None,
+    }
+}
+
+pub(crate) fn exp_to_json(e: &Exp, env: &GlobalEnv, resolver: &SpanResolver) -> ExpJson {
+    use move_model::ast::ExpData::*;
+    let raw_id = e.as_ref().node_id();
+    let node_id = raw_id.as_usize();
+    let loc = resolver.resolve(&env.get_node_loc(raw_id));
+    match e.as_ref() {
+        Invalid(_) => leaf(node_id, ExpKind::Invalid, None, loc),
+        Value(_, v) => leaf(node_id, ExpKind::Value, Some(format!("{v:?}")), loc),
+        LocalVar(_, sym) => leaf(
+            node_id,
+            ExpKind::LocalVar,
+            Some(sym.display(env.symbol_pool()).to_string()),
+            loc,
+        ),
+        Temporary(_, idx) => leaf(node_id, ExpKind::Temporary, Some(idx.to_string()), loc),
+        Call(_, oper, args) => ExpJson {
+            node_id,
+            kind: ExpKind::Call,
+            operation: Some(format!("{oper:?}")),
+            value: None,
+            bound_vars: vec![],
+            children: args.iter().map(|e| exp_to_json(e, env, resolver)).collect(),
+            loc,
+            spec: None,
+        },
+        Invoke(_, target, args) => ExpJson {
+            node_id,
+            kind: ExpKind::Invoke,
+            operation: None,
+            value: None,
+            bound_vars: vec![],
+            children: std::iter::once(exp_to_json(target, env, resolver))
+                .chain(args.iter().map(|e| exp_to_json(e, env, resolver)))
+                .collect(),
+            loc,
+            spec: None,
+        },
+        Lambda(_, pat, body, _capture_kind, _spec_opt) => ExpJson {
+            node_id,
+            kind: ExpKind::Lambda,
+            operation: None,
+            value: None,
+            bound_vars: pattern_vars(pat, env),
+            children: vec![exp_to_json(body, env, resolver)],
+            loc,
+            spec: None,
+        },
+        Quant(_, kind, ranges, triggers, condition, body) => ExpJson {
+            node_id,
+            kind: ExpKind::Quant,
+            operation: Some(format!("{kind:?}")),
+            value: None,
+            bound_vars: ranges
+                .iter()
+                .flat_map(|(p, _)| pattern_vars(p, env))
+                .collect(),
+            children: ranges
+                .iter()
+                .map(|(_, range)| exp_to_json(range, env, resolver))
+                .chain(triggers.iter().flatten().map(|e| exp_to_json(e, env, resolver)))
+                .chain(condition.iter().map(|e| exp_to_json(e, env, resolver)))
+                .chain(std::iter::once(exp_to_json(body, env, resolver)))
+                .collect(),
+            loc,
+            spec: None,
+        },
+        Block(_, pat, binding, body) => ExpJson {
+            node_id,
+            kind: ExpKind::Block,
+            operation: None,
+            value: None,
+            bound_vars: pattern_vars(pat, env),
+            children: binding
+                .iter()
+                .map(|e| exp_to_json(e, env, resolver))
+                .chain(std::iter::once(exp_to_json(body, env, resolver)))
+                .collect(),
+            loc,
+            spec: None,
+        },
+        IfElse(_, c, t, e2) => ExpJson {
+            node_id,
+            kind: ExpKind::IfElse,
+            operation: None,
+            value: None,
+            bound_vars: vec![],
+            children: vec![
+                exp_to_json(c, env, resolver),
+                exp_to_json(t, env, resolver),
+                exp_to_json(e2, env, resolver),
+            ],
+            loc,
+            spec: None,
+        },
+        Match(_, discriminant, arms) => ExpJson {
+            node_id,
+            kind: ExpKind::Match,
+            operation: None,
+            value: None,
+            bound_vars: vec![],
+            children: std::iter::once(exp_to_json(discriminant, env, resolver))
+                .chain(arms.iter().map(|arm| ExpJson {
+                    node_id: arm.pattern.node_id().as_usize(),
+                    kind: ExpKind::MatchArm,
+                    operation: None,
+                    value: None,
+                    bound_vars: pattern_vars(&arm.pattern, env),
+                    children: arm
+                        .condition
+                        .iter()
+                        .map(|e| exp_to_json(e, env, resolver))
+                        .chain(std::iter::once(exp_to_json(&arm.body, env, resolver)))
+                        .collect(),
+                    loc: resolver.resolve(&env.get_node_loc(arm.pattern.node_id())),
+                    spec: None,
+                }))
+                .collect(),
+            loc,
+            spec: None,
+        },
+        Return(_, vals) => ExpJson {
+            node_id,
+            kind: ExpKind::Return,
+            operation: None,
+            value: None,
+            bound_vars: vec![],
+            children: vec![exp_to_json(vals, env, resolver)],
+            loc,
+            spec: None,
+        },
+        Sequence(_, exps) => ExpJson {
+            node_id,
+            kind: ExpKind::Sequence,
+            operation: None,
+            value: None,
+            bound_vars: vec![],
+            children: exps.iter().map(|e| exp_to_json(e, env, resolver)).collect(),
+            loc,
+            spec: None,
+        },
+        Loop(_, body) => ExpJson {
+            node_id,
+            kind: ExpKind::Loop,
+            operation: None,
+            value: None,
+            bound_vars: vec![],
+            children: vec![exp_to_json(body, env, resolver)],
+            loc,
+            spec: None,
+        },
+        LoopCont(_, nest, is_continue) => leaf(
+            node_id,
+            if *is_continue {
+                ExpKind::LoopContinue
+            } else {
+                ExpKind::LoopBreak
+            },
+            Some(nest.to_string()),
+            loc,
+        ),
+        Assign(_, pat, rhs) => ExpJson {
+            node_id,
+            kind: ExpKind::Assign,
+            operation: None,
+            value: None,
+            bound_vars: pattern_vars(pat, env),
+            children: vec![exp_to_json(rhs, env, resolver)],
+            loc,
+            spec: None,
+        },
+        Mutate(_, lhs, rhs) => ExpJson {
+            node_id,
+            kind: ExpKind::Mutate,
+            operation: None,
+            value: None,
+            bound_vars: vec![],
+            children: vec![exp_to_json(lhs, env, resolver), exp_to_json(rhs, env, resolver)],
+            loc,
+            spec: None,
+        },
+        SpecBlock(_, spec) => ExpJson {
+            node_id,
+            kind: ExpKind::SpecBlock,
+            operation: None,
+            value: None,
+            bound_vars: vec![],
+            children: vec![],
+            loc,
+            spec: Some(spec_to_json(spec, env, resolver)),
+        },
+    }
+}
+
+fn struct_to_json(
+    s: &StructEnv,
+    env: &GlobalEnv,
+    module_id: &str,
+    module_path: &[String],
+    resolver: &SpanResolver,
+    index: &mut BTreeMap<String, Item>,
+    paths: &mut BTreeMap<String, ItemPath>,
+) -> String {
+    let tctx = s.get_type_display_ctx();
+    let name = s.get_name().display(env.symbol_pool()).to_string();
+    let id = schema::struct_id(module_id, &name);
+    let path: Vec<String> = module_path.iter().cloned().chain([name.clone()]).collect();
+
+    let fields = s
+        .get_fields()
+        .map(|f| field_to_json(&f, env, &tctx, &id, &path, resolver, index, paths))
+        .collect();
+
+    index.insert(
+        id.clone(),
+        Item::Struct(StructJson {
+            id: id.clone(),
+            module: module_id.to_string(),
+            name,
+            fields,
+            attrs: attrs_to_json(s.get_attributes(), env),
+            spec: spec_to_json(&s.get_spec(), env, resolver),
+            loc: resolver.resolve(&s.get_loc()),
+        }),
+    );
+    paths.insert(
+        id.clone(),
+        ItemPath {
+            kind: "struct".to_string(),
+            path,
+        },
+    );
+    id
+}
+
+fn field_to_json(
+    f: &FieldEnv,
+    env: &GlobalEnv,
+    tctx: &TypeDisplayContext,
+    struct_id: &str,
+    struct_path: &[String],
+    resolver: &SpanResolver,
+    index: &mut BTreeMap<String, Item>,
+    paths: &mut BTreeMap<String, ItemPath>,
+) -> String {
+    let name = f.get_name().display(env.symbol_pool()).to_string();
+    let id = schema::field_id(struct_id, &name);
+    let path: Vec<String> = struct_path.iter().cloned().chain([name.clone()]).collect();
+
+    index.insert(
+        id.clone(),
+        Item::Field(FieldJson {
+            id: id.clone(),
+            name,
+            ty: f.get_type().display(tctx).to_string(),
+            offset: f.get_offset(),
+            variant: f
+                .get_variant()
+                .map(|v| v.display(env.symbol_pool()).to_string()),
+            loc: resolver.resolve(&f.get_loc()),
+        }),
+    );
+    paths.insert(
+        id.clone(),
+        ItemPath {
+            kind: "field".to_string(),
+            path,
+        },
+    );
+    id
+}
+
+fn function_to_json(
+    f: &FunctionEnv,
+    env: &GlobalEnv,
+    module_id: &str,
+    module_path: &[String],
+    resolver: &SpanResolver,
+    index: &mut BTreeMap<String, Item>,
+    paths: &mut BTreeMap<String, ItemPath>,
+) -> String {
+    let tctx = f.get_type_display_ctx();
+    let name = f.get_name().display(env.symbol_pool()).to_string();
+    let id = schema::function_id(module_id, &name);
+    let path: Vec<String> = module_path.iter().cloned().chain([name.clone()]).collect();
+    // Parameters don't carry their own `Loc` in the model; the function's
+    // own location is used as a conservative stand-in (see `ast.rs`).
+    let fn_loc = resolver.resolve(&f.get_loc());
+
+    index.insert(
+        id.clone(),
+        Item::Function(FunJson {
+            id: id.clone(),
+            module: module_id.to_string(),
+            name,
+            params: f
+                .get_parameters()
+                .iter()
+                .map(|p| ParamJson {
+                    name: p.get_name().display(env.symbol_pool()).to_string(),
+                    ty: p.get_type().display(&tctx).to_string(),
+                    loc: fn_loc.clone(),
+                })
+                .collect(),
+            ret: f.get_result_type().display(&tctx).to_string(),
+            attrs: attrs_to_json(f.get_attributes(), env),
+            body: f.get_def().map(|d| exp_to_json(d, env, resolver)),
+            spec: spec_to_json(&f.get_spec(), env, resolver),
+            loc: fn_loc,
+        }),
+    );
+    paths.insert(
+        id.clone(),
+        ItemPath {
+            kind: "function".to_string(),
+            path,
+        },
+    );
+    id
+}
+
+fn module_to_json(
+    m: &ModuleEnv,
+    resolver: &SpanResolver,
+    index: &mut BTreeMap<String, Item>,
+    paths: &mut BTreeMap<String, ItemPath>,
+) -> String {
+    let env = m.env;
+    let address = env.display(m.get_name().addr()).to_string();
+    let name = m.get_name().name().display(m.symbol_pool()).to_string();
+    let id = schema::module_id(&address, &name);
+    let module_path = vec![name.clone()];
+
+    let structs = m
+        .get_structs()
+        .map(|s| struct_to_json(&s, env, &id, &module_path, resolver, index, paths))
+        .collect();
+    let functions = m
+        .get_functions()
+        .map(|f| function_to_json(&f, env, &id, &module_path, resolver, index, paths))
+        .collect();
+
+    index.insert(
+        id.clone(),
+        Item::Module(ModuleJson {
+            id: id.clone(),
+            name: name.clone(),
+            address,
+            structs,
+            functions,
+            attrs: attrs_to_json(m.get_attributes(), env),
+            spec: module_spec_to_json(m, env, resolver),
+            loc: resolver.resolve(&m.get_loc()),
+        }),
+    );
+    paths.insert(
+        id.clone(),
+        ItemPath {
+            kind: "module".to_string(),
+            path: module_path,
+        },
+    );
+    id
+}
+
+pub fn build_package(env: &GlobalEnv) -> PackageJson {
+    let resolver = SpanResolver::new(env);
+    let mut index = BTreeMap::new();
+    let mut paths = BTreeMap::new();
+    let modules = env
+        .get_modules()
+        .map(|m| module_to_json(&m, &resolver, &mut index, &mut paths))
+        .collect();
+
+    index.insert(schema::ROOT_ID.to_string(), Item::Package { modules });
+    paths.insert(
+        schema::ROOT_ID.to_string(),
+        ItemPath {
+            kind: "package".to_string(),
+            path: vec![],
+        },
+    );
+
+    PackageJson {
+        format_version: schema::FORMAT_VERSION,
+        root: schema::ROOT_ID.to_string(),
+        index,
+        paths,
+    }
+}
@@ -0,0 +1,15 @@
+//! Shared library behind the `move_ast_exporter` CLI's `summary`, `ast`,
+//! and `full` subcommands: package setup, compiler invocation, the JSON
+//! schema envelope, and the output renderers all live here so the
+//! subcommands only need to build a `Serialize` model and hand it to
+//! [`output::emit`].
+
+pub mod ast;
+pub mod full;
+pub mod manifest;
+pub mod output;
+pub mod package;
+pub mod schema;
+mod span;
+mod spec;
+pub mod summary;
@@ -0,0 +1,81 @@
+//! Minimal `Move.toml` reader.
+//!
+//! The compiler frontend only needs two tables out of a package manifest:
+//! `[addresses]` (named address bindings) and `[dependencies]` (other
+//! packages whose `sources` directory must be put on the compiler's
+//! dependency search path). This module parses just those tables and
+//! resolves dependency paths relative to the package root, the way
+//! `package.json` `"dependencies"` entries are resolved relative to the
+//! project root in Node tooling.
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Default, Deserialize)]
+struct RawManifest {
+    #[serde(default)]
+    addresses: BTreeMap<String, String>,
+    #[serde(default)]
+    dependencies: BTreeMap<String, RawDependency>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawDependency {
+    local: Option<String>,
+}
+
+/// The parts of a `Move.toml` the compiler needs: named addresses in
+/// `name=0x...` form, and resolved `sources` directories for every local
+/// dependency.
+#[derive(Debug, Default)]
+pub struct Manifest {
+    pub named_addresses: Vec<String>,
+    pub dependency_sources: Vec<String>,
+}
+
+impl Manifest {
+    /// Read and resolve `Move.toml` under `pkg_root`.
+    pub fn load(pkg_root: &Path) -> Result<Manifest> {
+        let manifest_path = pkg_root.join("Move.toml");
+        let text = fs::read_to_string(&manifest_path)
+            .with_context(|| format!("reading {}", manifest_path.display()))?;
+        let raw: RawManifest = toml::from_str(&text)
+            .with_context(|| format!("parsing {}", manifest_path.display()))?;
+
+        let named_addresses = raw
+            .addresses
+            .into_iter()
+            .map(|(name, addr)| format!("{name}={addr}"))
+            .collect();
+
+        let mut dependency_sources = Vec::new();
+        for (name, dep) in raw.dependencies {
+            match dep.local {
+                Some(rel) => dependency_sources.push(
+                    pkg_root
+                        .join(rel)
+                        .join("sources")
+                        .to_string_lossy()
+                        .into_owned(),
+                ),
+                // `git`/`rev`/`subdir` dependencies aren't fetched by this
+                // reader (no checkout to point the compiler at), so they're
+                // dropped from the search path rather than silently treated
+                // as absent.
+                None => eprintln!(
+                    "warning: dependency `{name}` in {} has no `local` path and was skipped \
+                     (git/rev/subdir dependencies aren't resolved)",
+                    manifest_path.display()
+                ),
+            }
+        }
+
+        Ok(Manifest {
+            named_addresses,
+            dependency_sources,
+        })
+    }
+}
@@ -0,0 +1,43 @@
+//! Renders any of the exporters' `Serialize` models in the format the
+//! caller asked for, and writes it to a file or stdout.
+//!
+//! `Toml` is the weakest-supported of the three: TOML has no `null`, so
+//! every optional field on the exported models (e.g. `loc`) is marked
+//! `#[serde(skip_serializing_if = "Option::is_none")]` to avoid it, and the
+//! `toml` crate's support for the internally-tagged `item_kind` enum used
+//! by `ast`/`full`'s `Item` is less battle-tested than `serde_json`'s or
+//! `serde_yaml`'s. `summary`, which has no enums or `None`s in its model,
+//! is the safest bet for `--format toml`; prefer `json`/`yaml` for `ast`
+//! and `full` until that's been exercised against a real package.
+
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Format {
+    Json,
+    Yaml,
+    Toml,
+}
+
+pub fn render<T: Serialize>(value: &T, format: Format) -> Result<String> {
+    Ok(match format {
+        Format::Json => serde_json::to_string_pretty(value)?,
+        Format::Yaml => serde_yaml::to_string(value)?,
+        Format::Toml => toml::to_string_pretty(value)?,
+    })
+}
+
+/// Write `value` rendered as `format` to `output`, or to stdout if `output` is `None`.
+pub fn emit<T: Serialize>(value: &T, format: Format, output: Option<&Path>) -> Result<()> {
+    let rendered = render(value, format)?;
+    match output {
+        Some(path) => fs::write(path, rendered)
+            .with_context(|| format!("writing {}", path.display()))?,
+        None => println!("{rendered}"),
+    }
+    Ok(())
+}
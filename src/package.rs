@@ -0,0 +1,62 @@
+//! Shared package-setup and compiler-invocation logic used by every
+//! subcommand: resolve the input path to a package root (synthesizing a
+//! scratch package for a bare `.move` file), then run the compiler and
+//! hand back the resulting `GlobalEnv`.
+
+use crate::manifest::Manifest;
+use anyhow::{bail, Context, Result};
+use move_compiler_v2::{run_move_compiler_to_stderr, Options as CompilerOptions};
+use move_model::model::GlobalEnv;
+use std::fs;
+use std::path::Path;
+
+/// Compile the package at `path` (a package directory, or a bare
+/// `.move` file to wrap in a scratch package) and return its `GlobalEnv`.
+///
+/// `named_address_overrides` only takes effect for the bare-file case: a
+/// real package directory's addresses always come from its `Move.toml`.
+pub fn compile_package(path: &Path, named_address_overrides: Vec<String>) -> Result<GlobalEnv> {
+    let pkg_root;
+    let named_address_mapping;
+    let dependencies;
+    // Keeps the scratch package's temp dir alive until after compilation.
+    let _scratch_dir;
+
+    if path.is_dir() {
+        let manifest = Manifest::load(path)?;
+        pkg_root = path.to_path_buf();
+        named_address_mapping = manifest.named_addresses;
+        dependencies = manifest.dependency_sources;
+        _scratch_dir = None;
+    } else {
+        let tmp = tempfile::tempdir().context("create temp package")?;
+        let root = tmp.path().to_path_buf();
+        fs::write(
+            root.join("Move.toml"),
+            "[package]\nname = \"scratch\"\nversion = \"0.0.0\"\n",
+        )?;
+        let src_dir = root.join("sources");
+        fs::create_dir_all(&src_dir)?;
+        fs::copy(path, src_dir.join("main.move"))?;
+
+        named_address_mapping = if named_address_overrides.is_empty() {
+            vec!["BasicCoin=0x1".to_string(), "std=0x1".to_string()]
+        } else {
+            named_address_overrides
+        };
+        dependencies = vec!["../aptos-core/third_party/move/move-stdlib/sources".to_string()];
+        pkg_root = root;
+        _scratch_dir = Some(tmp);
+    }
+
+    let mut opts = CompilerOptions::default();
+    opts.sources = vec![pkg_root.join("sources").to_string_lossy().into()];
+    opts.named_address_mapping = named_address_mapping;
+    opts.dependencies = dependencies;
+
+    let (env, _units) = run_move_compiler_to_stderr(opts)?;
+    if env.has_errors() {
+        bail!("Compilation failed");
+    }
+    Ok(env)
+}
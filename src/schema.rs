@@ -0,0 +1,42 @@
+//! Shared envelope for the exporters' JSON output.
+//!
+//! Mirrors the shape rustdoc's JSON backend uses: a `format_version` so
+//! consumers can detect breaking changes, and an `index` of items keyed by
+//! stable string ids with a `paths` side-table for cross-referencing an id
+//! without re-walking the tree. An id is stable across runs for the same
+//! source: it is built from the module's address/name and the `::`-separated
+//! path of the item inside it, never from traversal order.
+//!
+//! Bump `FORMAT_VERSION` whenever a field is added, removed, or changes
+//! meaning.
+
+use serde::Serialize;
+
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Id of the synthetic package item that roots the `index`.
+pub const ROOT_ID: &str = "package";
+
+pub fn module_id(address: &str, name: &str) -> String {
+    format!("{address}::{name}")
+}
+
+pub fn struct_id(module_id: &str, name: &str) -> String {
+    format!("{module_id}::struct::{name}")
+}
+
+pub fn field_id(struct_id: &str, name: &str) -> String {
+    format!("{struct_id}::{name}")
+}
+
+pub fn function_id(module_id: &str, name: &str) -> String {
+    format!("{module_id}::fun::{name}")
+}
+
+/// A cross-reference summary for an id: enough to label a reference
+/// without looking it up in `index`.
+#[derive(Serialize)]
+pub struct ItemPath {
+    pub kind: String,
+    pub path: Vec<String>,
+}
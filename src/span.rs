@@ -0,0 +1,71 @@
+//! Resolves a `move_model::model::Loc` to a JSON-friendly source span.
+//!
+//! `GlobalEnv` implements `codespan_reporting`'s `Files` trait (it backs
+//! the prover's diagnostic output), so `location()` gives us line/column
+//! for a byte offset; the span's byte offsets come straight off `Loc`.
+//! File names are stringified once per file id and cached, since a
+//! package with many items in the same file would otherwise re-format
+//! the same path on every node.
+
+use codespan_reporting::files::Files;
+use move_model::model::{FileId, GlobalEnv, Loc};
+use serde::Serialize;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[derive(Serialize, Clone)]
+pub(crate) struct SpanJson {
+    file: String,
+    start_line: usize,
+    start_col: usize,
+    end_line: usize,
+    end_col: usize,
+    byte_start: usize,
+    byte_end: usize,
+}
+
+pub(crate) struct SpanResolver<'a> {
+    env: &'a GlobalEnv,
+    file_names: RefCell<HashMap<FileId, Rc<str>>>,
+}
+
+impl<'a> SpanResolver<'a> {
+    pub fn new(env: &'a GlobalEnv) -> Self {
+        SpanResolver {
+            env,
+            file_names: RefCell::new(HashMap::new()),
+        }
+    }
+
+    fn file_name(&self, file_id: FileId) -> Rc<str> {
+        if let Some(cached) = self.file_names.borrow().get(&file_id) {
+            return cached.clone();
+        }
+        let name: Rc<str> = self
+            .env
+            .name(file_id)
+            .map(|n| n.to_string().into())
+            .unwrap_or_else(|_| Rc::from("<unknown>"));
+        self.file_names.borrow_mut().insert(file_id, name.clone());
+        name
+    }
+
+    pub fn resolve(&self, loc: &Loc) -> Option<SpanJson> {
+        let file_id = loc.file_id();
+        let span = loc.span();
+        let byte_start: usize = span.start().into();
+        let byte_end: usize = span.end().into();
+        let start = self.env.location(file_id, byte_start).ok()?;
+        let end = self.env.location(file_id, byte_end).ok()?;
+        Some(SpanJson {
+            file: self.file_name(file_id).to_string(),
+            start_line: start.line_number,
+            start_col: start.column_number,
+            end_line: end.line_number,
+            end_col: end.column_number,
+            byte_start,
+            byte_end,
+        })
+    }
+}
@@ -0,0 +1,114 @@
+//! Serializes the Move specification language: `requires`/`ensures`/
+//! `aborts_if`/invariants and their properties, attached to functions,
+//! structs, and modules. Condition expressions are exported by reusing
+//! [`crate::full::exp_to_json`], so a spec's expressions have the same
+//! fidelity as a function's body.
+//!
+//! Module-level `invariant ..` declarations (inside `spec module { .. }`)
+//! aren't part of `ModuleEnv::get_spec()` — the model tracks them
+//! separately as global invariants keyed by the module(s) whose memory
+//! they mention — so [`module_spec_to_json`] pulls those in from
+//! `GlobalEnv::get_global_invariants_by_module` and appends them to the
+//! module's own conditions.
+//!
+//! `spec schema Foo { .. }` definitions are inlined by the compiler at
+//! their use sites and are not retained as separate objects on
+//! `FunctionEnv`/`StructEnv`/`ModuleEnv`, so there is nothing further to
+//! export for them beyond the conditions below.
+
+use crate::full::{exp_to_json, ExpJson};
+use crate::span::SpanResolver;
+use move_model::ast::{Condition, Spec};
+use move_model::model::{GlobalEnv, GlobalInvariant, ModuleEnv};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct PropertyJson {
+    name: String,
+    value: String,
+}
+
+#[derive(Serialize)]
+struct ConditionJson {
+    kind: String,
+    properties: Vec<PropertyJson>,
+    exp: ExpJson,
+    additional_exps: Vec<ExpJson>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct SpecJson {
+    conditions: Vec<ConditionJson>,
+}
+
+fn condition_to_json(c: &Condition, env: &GlobalEnv, resolver: &SpanResolver) -> ConditionJson {
+    ConditionJson {
+        kind: format!("{:?}", c.kind),
+        properties: c
+            .properties
+            .iter()
+            .map(|(name, value)| PropertyJson {
+                name: name.display(env.symbol_pool()).to_string(),
+                value: format!("{value:?}"),
+            })
+            .collect(),
+        exp: exp_to_json(&c.exp, env, resolver),
+        additional_exps: c
+            .additional_exps
+            .iter()
+            .map(|e| exp_to_json(e, env, resolver))
+            .collect(),
+    }
+}
+
+pub(crate) fn spec_to_json(spec: &Spec, env: &GlobalEnv, resolver: &SpanResolver) -> SpecJson {
+    SpecJson {
+        conditions: spec
+            .conditions
+            .iter()
+            .map(|c| condition_to_json(c, env, resolver))
+            .collect(),
+    }
+}
+
+fn global_invariant_to_json(
+    inv: &GlobalInvariant,
+    env: &GlobalEnv,
+    resolver: &SpanResolver,
+) -> ConditionJson {
+    ConditionJson {
+        kind: format!("{:?}", inv.kind),
+        properties: inv
+            .properties
+            .iter()
+            .map(|(name, value)| PropertyJson {
+                name: name.display(env.symbol_pool()).to_string(),
+                value: format!("{value:?}"),
+            })
+            .collect(),
+        exp: exp_to_json(&inv.cond, env, resolver),
+        additional_exps: vec![],
+    }
+}
+
+/// Like [`spec_to_json`], but for a module: also folds in the module's
+/// `spec module { invariant .. }` declarations, which the model tracks as
+/// global invariants rather than as part of `ModuleEnv::get_spec()`.
+pub(crate) fn module_spec_to_json(
+    m: &ModuleEnv,
+    env: &GlobalEnv,
+    resolver: &SpanResolver,
+) -> SpecJson {
+    let mut conditions: Vec<ConditionJson> = m
+        .get_spec()
+        .conditions
+        .iter()
+        .map(|c| condition_to_json(c, env, resolver))
+        .collect();
+    conditions.extend(
+        env.get_global_invariants_by_module(m.get_id())
+            .into_iter()
+            .map(|inv| global_invariant_to_json(inv, env, resolver)),
+    );
+    SpecJson { conditions }
+}
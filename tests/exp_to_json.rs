@@ -0,0 +1,92 @@
+//! Compiles `tests/fixtures/exp_kinds.move`, a fixture written to exercise
+//! every `ExpKind` variant (function values/`invoke`, `match`, `loop`/
+//! `continue`/`break`, `mutate`, inline `spec {}` blocks, and a module-level
+//! `spec module { invariant .. }`), and asserts each variant's tag shows up
+//! in `full::build_package`'s serialized output. `exp_to_json`'s `ExpKind`
+//! is crate-private, so this checks the public JSON shape rather than the
+//! private enum directly.
+//!
+//! `Invalid` is intentionally left out: `compile_package` bails on compile
+//! errors, so a successfully compiled fixture never produces one.
+
+use move_ast_exporter::{full, package};
+use serde_json::Value;
+use std::path::Path;
+
+/// Walks every `Value::Object`/`Value::Array` node in `value`, calling `f`
+/// on each object encountered, so a `kind`-tagged expression node anywhere
+/// in the export (regardless of nesting depth) gets visited.
+fn for_each_object<'a>(value: &'a Value, f: &mut impl FnMut(&'a serde_json::Map<String, Value>)) {
+    match value {
+        Value::Object(map) => {
+            f(map);
+            for v in map.values() {
+                for_each_object(v, f);
+            }
+        }
+        Value::Array(items) => {
+            for v in items {
+                for_each_object(v, f);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[test]
+fn exp_to_json_covers_every_exp_kind() {
+    let env = package::compile_package(Path::new("tests/fixtures/exp_kinds.move"), vec![])
+        .expect("fixture package should compile");
+    let json = serde_json::to_string(&full::build_package(&env)).expect("serialize package");
+
+    for kind in [
+        "value",
+        "local_var",
+        "temporary",
+        "call",
+        "invoke",
+        "lambda",
+        "quant",
+        "block",
+        "if_else",
+        "match",
+        "match_arm",
+        "return",
+        "sequence",
+        "loop",
+        "loop_continue",
+        "loop_break",
+        "assign",
+        "mutate",
+        "spec_block",
+    ] {
+        let tag = format!("\"kind\":\"{kind}\"");
+        assert!(json.contains(&tag), "missing ExpKind::{kind} in exported AST");
+    }
+}
+
+/// A `spec_block` node's payload must be the same structured `SpecJson`
+/// shape used for function/struct/module specs, not a `Debug`-formatted
+/// string of the raw `Spec` — that regressed once already (see the
+/// `chunk0-3` fix commit), so pin it down structurally instead of just
+/// checking the `spec_block` tag shows up somewhere in the output.
+#[test]
+fn spec_block_payload_is_structured() {
+    let env = package::compile_package(Path::new("tests/fixtures/exp_kinds.move"), vec![])
+        .expect("fixture package should compile");
+    let value: Value =
+        serde_json::to_value(full::build_package(&env)).expect("serialize package");
+
+    let mut found_spec_block = false;
+    for_each_object(&value, &mut |node| {
+        if node.get("kind").and_then(Value::as_str) == Some("spec_block") {
+            found_spec_block = true;
+            let spec = node.get("spec").expect("spec_block node must carry a `spec` field");
+            assert!(
+                spec.get("conditions").is_some_and(Value::is_array),
+                "spec_block's `spec` must be a structured SpecJson object, not a Debug string: {spec:?}"
+            );
+        }
+    });
+    assert!(found_spec_block, "fixture should produce at least one spec_block node");
+}